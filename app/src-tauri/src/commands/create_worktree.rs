@@ -0,0 +1,130 @@
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread::JoinHandle;
+
+use serde::Serialize;
+use tauri::Window;
+
+use crate::config::{MaelstromConfig, ProjectConfig};
+use crate::paths::repo_root;
+use crate::vcs::git;
+
+/// Emitted on the `worktree-task-output` channel as each post-create task
+/// produces output, so the frontend can stream it live.
+#[derive(Debug, Clone, Serialize)]
+struct TaskOutputEvent {
+    worktree: String,
+    task: String,
+    line: String,
+}
+
+/// Creates a worktree for `branch` off `project` (as declared in
+/// `maelstrom.yaml`) and runs that project's configured post-create
+/// tasks in it, streaming their output to the frontend.
+#[tauri::command]
+pub async fn create_worktree(
+    window: Window,
+    project: String,
+    branch: String,
+) -> Result<(), String> {
+    let config = MaelstromConfig::load(&repo_root())
+        .map_err(|e| format!("Failed to load maelstrom.yaml: {}", e))?;
+
+    let project_config = config
+        .projects
+        .iter()
+        .find(|p| p.name == project)
+        .ok_or_else(|| format!("Unknown project '{}' in maelstrom.yaml", project))?
+        .clone();
+
+    let path = Path::new(&project_config.path)
+        .join("worktrees")
+        .join(&branch);
+
+    git::create_worktree(Path::new(&project_config.path), &branch, &path)
+        .map_err(|e| format!("Failed to create worktree: {}", e))?;
+
+    // Tasks are run synchronously (pipes drained, process waited on) on a
+    // blocking thread so a slow `npm install`/`cargo build` doesn't tie up
+    // a tokio worker for the duration.
+    tauri::async_runtime::spawn_blocking(move || {
+        run_tasks(&window, &branch, &path, &project_config)
+    })
+    .await
+    .map_err(|e| format!("Task runner panicked: {}", e))?
+}
+
+fn run_tasks(
+    window: &Window,
+    worktree: &str,
+    cwd: &Path,
+    project_config: &ProjectConfig,
+) -> Result<(), String> {
+    for task in &project_config.tasks {
+        run_task(window, worktree, cwd, task)?;
+    }
+    Ok(())
+}
+
+fn run_task(window: &Window, worktree: &str, cwd: &Path, task: &str) -> Result<(), String> {
+    let mut child = Command::new("sh")
+        .args(["-c", task])
+        .current_dir(cwd)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to start task '{}': {}", task, e))?;
+
+    let stdout = child.stdout.take().expect("stdout was piped");
+    let stderr = child.stderr.take().expect("stderr was piped");
+
+    // Drain stdout and stderr concurrently: once either pipe's OS buffer
+    // fills, the child blocks on write until it's read, so reading only
+    // one stream can deadlock a task that logs progress to the other.
+    let stdout_reader = spawn_output_reader(
+        window.clone(),
+        worktree.to_string(),
+        task.to_string(),
+        stdout,
+    );
+    let stderr_reader = spawn_output_reader(
+        window.clone(),
+        worktree.to_string(),
+        task.to_string(),
+        stderr,
+    );
+
+    stdout_reader.join().expect("stdout reader thread panicked");
+    stderr_reader.join().expect("stderr reader thread panicked");
+
+    let status = child
+        .wait()
+        .map_err(|e| format!("Failed to wait for task '{}': {}", task, e))?;
+
+    if !status.success() {
+        return Err(format!("Task '{}' exited with status {}", task, status));
+    }
+
+    Ok(())
+}
+
+fn spawn_output_reader(
+    window: Window,
+    worktree: String,
+    task: String,
+    reader: impl Read + Send + 'static,
+) -> JoinHandle<()> {
+    std::thread::spawn(move || {
+        for line in BufReader::new(reader).lines().map_while(Result::ok) {
+            let _ = window.emit(
+                "worktree-task-output",
+                TaskOutputEvent {
+                    worktree: worktree.clone(),
+                    task: task.clone(),
+                    line,
+                },
+            );
+        }
+    })
+}
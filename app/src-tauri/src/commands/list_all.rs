@@ -1,6 +1,20 @@
-use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::path::PathBuf;
-use std::process::Command;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tauri::{State, Window};
+use tokio::sync::Semaphore;
+
+use crate::config::MaelstromConfig;
+use crate::ide::IdeState;
+use crate::paths::repo_root;
+use crate::vcs::{self, BackendKind};
+
+/// Scans at most this many projects concurrently, so a large
+/// `maelstrom.yaml` doesn't run an unbounded number of repository scans
+/// at once.
+const MAX_CONCURRENT_SCANS: usize = 4;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Worktree {
@@ -15,47 +29,200 @@ pub struct Worktree {
     pub pr_commits: Option<u32>,
     pub pushed_commits: Option<u32>,
     pub ide_active: bool,
+    pub submodules: Vec<SubmoduleStatus>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SubmoduleStatus {
+    pub name: String,
+    pub path: String,
+    pub initialized: bool,
+    pub dirty: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Project {
     pub name: String,
     pub path: String,
+    pub backend: BackendKind,
     pub worktrees: Vec<Worktree>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ListAllResponse {
     pub projects: Vec<Project>,
+    /// Projects whose scan failed; reported alongside `projects` rather
+    /// than failing the whole call, so one bad project (e.g. an
+    /// unsupported backend, or a read error) doesn't hide the rest.
+    #[serde(default)]
+    pub errors: Vec<ProjectScanError>,
 }
 
-/// Returns the repo root directory (parent of the `app/` directory).
-fn repo_root() -> PathBuf {
-    let mut dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-    // CARGO_MANIFEST_DIR points to app/src-tauri, go up twice to reach repo root
-    dir.pop(); // app/
-    dir.pop(); // repo root
-    dir
+/// A project that failed to scan, and why.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProjectScanError {
+    pub name: String,
+    pub path: String,
+    pub error: String,
 }
 
-#[tauri::command]
-pub async fn list_all() -> Result<ListAllResponse, String> {
+/// Emitted on the `project-scanned` channel as each project's status
+/// resolves, so the frontend can render progressively.
+#[derive(Debug, Clone, Serialize)]
+struct ProjectScannedEvent {
+    project: Project,
+}
+
+struct ProjectSource {
+    name: String,
+    path: PathBuf,
+}
+
+/// Returns the projects to scan: those declared in `maelstrom.yaml`, or
+/// (when there's no config, or it declares none) the single project
+/// rooted at the repo itself.
+fn discover_project_sources() -> Result<Vec<ProjectSource>, String> {
     let root = repo_root();
+    let config = MaelstromConfig::load(&root)
+        .map_err(|e| format!("Failed to load maelstrom.yaml: {}", e))?;
+
+    if config.projects.is_empty() {
+        let name = root
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| root.to_string_lossy().into_owned());
+        return Ok(vec![ProjectSource { name, path: root }]);
+    }
+
+    Ok(config
+        .projects
+        .into_iter()
+        .map(|p| ProjectSource {
+            name: p.name,
+            path: PathBuf::from(p.path),
+        })
+        .collect())
+}
 
-    let output = Command::new("uv")
-        .args(["run", "mael", "--json", "list-all"])
-        .current_dir(&root)
-        .output()
-        .map_err(|e| format!("Failed to execute uv run mael: {}", e))?;
+fn scan_project(source: &ProjectSource, active_ides: &HashSet<String>) -> Result<Project, String> {
+    let backend = vcs::detect_backend(&source.path).ok_or_else(|| {
+        format!(
+            "No recognized VCS (.git/.jj/.hg) found at {}",
+            source.path.display()
+        )
+    })?;
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("mael list-all failed: {}", stderr));
+    let mut worktrees = backend
+        .discover(&source.path)
+        .map_err(|e| format!("Failed to scan worktrees for '{}': {}", source.name, e))?;
+
+    for worktree in &mut worktrees {
+        worktree.ide_active = active_ides.contains(&worktree.name);
     }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let response: ListAllResponse = serde_json::from_str(&stdout)
-        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+    Ok(Project {
+        name: source.name.clone(),
+        path: source.path.to_string_lossy().into_owned(),
+        backend: backend.kind(),
+        worktrees,
+    })
+}
+
+/// Scans every configured project concurrently (bounded by
+/// [`MAX_CONCURRENT_SCANS`]), emitting a `project-scanned` or
+/// `project-scan-failed` event as each one resolves, and a final
+/// `scan-complete` event once all are done — a single project failing
+/// (e.g. an unsupported backend) never prevents the others from being
+/// reported, or `scan-complete` from firing.
+#[tauri::command]
+pub async fn list_all_stream(window: Window, ide_state: State<'_, IdeState>) -> Result<(), String> {
+    let sources = discover_project_sources()?;
+    let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_SCANS));
+    let active_ides = ide_state.active();
+
+    let handles: Vec<_> = sources
+        .into_iter()
+        .map(|source| {
+            let semaphore = semaphore.clone();
+            let window = window.clone();
+            let active_ides = active_ides.clone();
+            tauri::async_runtime::spawn(async move {
+                let _permit = semaphore
+                    .acquire_owned()
+                    .await
+                    .expect("scan semaphore should not be closed");
+
+                match scan_project(&source, &active_ides) {
+                    Ok(project) => {
+                        let _ = window.emit("project-scanned", ProjectScannedEvent { project });
+                    }
+                    Err(error) => {
+                        let _ = window.emit(
+                            "project-scan-failed",
+                            ProjectScanError {
+                                name: source.name.clone(),
+                                path: source.path.to_string_lossy().into_owned(),
+                                error,
+                            },
+                        );
+                    }
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle
+            .await
+            .map_err(|e| format!("Scan task panicked: {}", e))?;
+    }
+
+    let _ = window.emit("scan-complete", ());
+    Ok(())
+}
+
+/// Backward-compatible, non-streaming variant: scans every configured
+/// project the same way as [`list_all_stream`], but collects the results
+/// into a single [`ListAllResponse`] instead of emitting events. A
+/// project that fails to scan is reported in `errors`, not raised as the
+/// call's overall error.
+#[tauri::command]
+pub async fn list_all(ide_state: State<'_, IdeState>) -> Result<ListAllResponse, String> {
+    let sources = discover_project_sources()?;
+    let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_SCANS));
+    let active_ides = ide_state.active();
+
+    let handles: Vec<_> = sources
+        .into_iter()
+        .map(|source| {
+            let semaphore = semaphore.clone();
+            let active_ides = active_ides.clone();
+            tauri::async_runtime::spawn(async move {
+                let _permit = semaphore
+                    .acquire_owned()
+                    .await
+                    .expect("scan semaphore should not be closed");
+
+                scan_project(&source, &active_ides).map_err(|error| ProjectScanError {
+                    name: source.name.clone(),
+                    path: source.path.to_string_lossy().into_owned(),
+                    error,
+                })
+            })
+        })
+        .collect();
+
+    let mut projects = Vec::with_capacity(handles.len());
+    let mut errors = Vec::new();
+    for handle in handles {
+        match handle
+            .await
+            .map_err(|e| format!("Scan task panicked: {}", e))?
+        {
+            Ok(project) => projects.push(project),
+            Err(error) => errors.push(error),
+        }
+    }
 
-    Ok(response)
+    Ok(ListAllResponse { projects, errors })
 }
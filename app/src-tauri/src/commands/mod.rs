@@ -0,0 +1,5 @@
+pub mod create_worktree;
+pub mod list_all;
+pub mod open_in_ide;
+pub mod push_worktree;
+pub mod sync_submodules;
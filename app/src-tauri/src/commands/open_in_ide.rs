@@ -0,0 +1,36 @@
+use tauri::State;
+
+use crate::config::MaelstromConfig;
+use crate::ide::{self, IdeState};
+use crate::paths::repo_root;
+
+const DEFAULT_EDITOR: &str = "code";
+
+/// Prepares `worktree_name` for editing (generating `rust-project.json`
+/// when it's a cargo project) and launches the configured editor against
+/// it, marking the worktree `ide_active`.
+#[tauri::command]
+pub async fn open_in_ide(worktree_name: String, state: State<'_, IdeState>) -> Result<(), String> {
+    let config = MaelstromConfig::load(&repo_root())
+        .map_err(|e| format!("Failed to load maelstrom.yaml: {}", e))?;
+    let (_, path) = config
+        .find_worktree_owner(&worktree_name, &repo_root())
+        .ok_or_else(|| {
+            format!(
+                "No worktree named '{}' found in any configured project",
+                worktree_name
+            )
+        })?;
+
+    if path.join("Cargo.toml").is_file() {
+        ide::write_rust_project_json(&path)
+            .map_err(|e| format!("Failed to generate rust-project.json: {}", e))?;
+    }
+
+    let editor = std::env::var("MAELSTROM_EDITOR").unwrap_or_else(|_| DEFAULT_EDITOR.to_string());
+    ide::launch_editor(&editor, &path).map_err(|e| format!("Failed to open editor: {}", e))?;
+
+    state.mark_active(&worktree_name);
+
+    Ok(())
+}
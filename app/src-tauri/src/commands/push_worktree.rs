@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::MaelstromConfig;
+use crate::paths::repo_root;
+use crate::vcs::git;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RemotePushResult {
+    pub remote: String,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Pushes `worktree_name`'s branch to each of `remotes`, or, when
+/// `remotes` is `None`, to every remote configured for its owning
+/// project (default plus mirrors).
+#[tauri::command]
+pub async fn push_worktree(
+    worktree_name: String,
+    remotes: Option<Vec<String>>,
+) -> Result<Vec<RemotePushResult>, String> {
+    let config = MaelstromConfig::load(&repo_root())
+        .map_err(|e| format!("Failed to load maelstrom.yaml: {}", e))?;
+    let (project_config, path) = config
+        .find_worktree_owner(&worktree_name, &repo_root())
+        .ok_or_else(|| {
+            format!(
+                "No worktree named '{}' found in any configured project",
+                worktree_name
+            )
+        })?;
+
+    let targets = remotes.unwrap_or_else(|| project_config.remotes.all_remotes());
+
+    let results = targets
+        .into_iter()
+        .map(|remote| match git::push_branch(&path, &remote) {
+            Ok(()) => RemotePushResult {
+                remote,
+                success: true,
+                error: None,
+            },
+            Err(e) => RemotePushResult {
+                remote,
+                success: false,
+                error: Some(e.to_string()),
+            },
+        })
+        .collect();
+
+    Ok(results)
+}
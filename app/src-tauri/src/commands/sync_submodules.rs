@@ -0,0 +1,19 @@
+use crate::config::MaelstromConfig;
+use crate::paths::repo_root;
+use crate::vcs::git;
+
+#[tauri::command]
+pub async fn sync_submodules(worktree_name: String) -> Result<(), String> {
+    let config = MaelstromConfig::load(&repo_root())
+        .map_err(|e| format!("Failed to load maelstrom.yaml: {}", e))?;
+    let (_, path) = config
+        .find_worktree_owner(&worktree_name, &repo_root())
+        .ok_or_else(|| {
+            format!(
+                "No worktree named '{}' found in any configured project",
+                worktree_name
+            )
+        })?;
+
+    git::sync_submodules(&path).map_err(|e| format!("Failed to sync submodules: {}", e))
+}
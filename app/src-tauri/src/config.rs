@@ -0,0 +1,196 @@
+//! Per-project configuration, loaded from a `maelstrom.yaml` at the repo
+//! root.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("failed to read {path}: {source}")]
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("failed to parse {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        source: serde_yaml::Error,
+    },
+}
+
+/// Top-level contents of `maelstrom.yaml`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct MaelstromConfig {
+    #[serde(default)]
+    pub projects: Vec<ProjectConfig>,
+}
+
+impl MaelstromConfig {
+    /// Loads `maelstrom.yaml` from `repo_root`, or returns an empty config
+    /// if the file doesn't exist.
+    pub fn load(repo_root: &Path) -> Result<Self, ConfigError> {
+        let path = repo_root.join("maelstrom.yaml");
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(&path).map_err(|e| ConfigError::Read {
+            path: path.clone(),
+            source: e,
+        })?;
+        serde_yaml::from_str(&contents).map_err(|e| ConfigError::Parse { path, source: e })
+    }
+
+    /// Finds the project owning `worktree_name`, i.e. the configured
+    /// project whose `<path>/worktrees/<worktree_name>` directory exists.
+    /// When no projects are declared, `fallback_root` itself is treated
+    /// as the sole project, the same fallback `list_all` uses when
+    /// discovering projects.
+    pub fn find_worktree_owner(
+        &self,
+        worktree_name: &str,
+        fallback_root: &Path,
+    ) -> Option<(ProjectConfig, PathBuf)> {
+        let fallback;
+        let candidates: &[ProjectConfig] = if self.projects.is_empty() {
+            fallback = [ProjectConfig {
+                name: fallback_root
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| fallback_root.to_string_lossy().into_owned()),
+                path: fallback_root.to_string_lossy().into_owned(),
+                remotes: RemoteConfig::default(),
+                tasks: Vec::new(),
+            }];
+            &fallback
+        } else {
+            &self.projects
+        };
+
+        candidates.iter().find_map(|project| {
+            let worktree_path = Path::new(&project.path)
+                .join("worktrees")
+                .join(worktree_name);
+            worktree_path
+                .is_dir()
+                .then(|| (project.clone(), worktree_path))
+        })
+    }
+}
+
+/// Configuration for a single project declared in `maelstrom.yaml`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProjectConfig {
+    pub name: String,
+    pub path: String,
+    #[serde(default)]
+    pub remotes: RemoteConfig,
+    /// Shell tasks run, in order, in a freshly created worktree
+    /// (`npm install`, `uv sync`, codegen, ...).
+    #[serde(default)]
+    pub tasks: Vec<String>,
+}
+
+/// Remote push configuration for a project: a default remote plus any
+/// additional mirrors that should be kept in sync (e.g. a GitHub origin
+/// and an internal GitLab mirror).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RemoteConfig {
+    pub default_remote: String,
+    #[serde(default)]
+    pub mirror_remotes: Vec<String>,
+}
+
+impl Default for RemoteConfig {
+    fn default() -> Self {
+        Self {
+            default_remote: "origin".to_string(),
+            mirror_remotes: Vec::new(),
+        }
+    }
+}
+
+impl RemoteConfig {
+    /// All configured remotes, default first.
+    pub fn all_remotes(&self) -> Vec<String> {
+        std::iter::once(self.default_remote.clone())
+            .chain(self.mirror_remotes.iter().cloned())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(name: &str, root: &Path) -> ProjectConfig {
+        ProjectConfig {
+            name: name.to_string(),
+            path: root.join(name).to_string_lossy().into_owned(),
+            remotes: RemoteConfig::default(),
+            tasks: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn find_worktree_owner_falls_back_to_repo_root_when_no_projects_declared() {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir_all(root.path().join("worktrees/my-branch")).unwrap();
+
+        let config = MaelstromConfig::default();
+        let (owner, path) = config
+            .find_worktree_owner("my-branch", root.path())
+            .expect("fallback project should own the worktree");
+
+        assert_eq!(owner.path, root.path().to_string_lossy());
+        assert_eq!(path, root.path().join("worktrees/my-branch"));
+    }
+
+    #[test]
+    fn find_worktree_owner_returns_none_when_fallback_has_no_such_worktree() {
+        let root = tempfile::tempdir().unwrap();
+
+        let config = MaelstromConfig::default();
+        assert!(config
+            .find_worktree_owner("my-branch", root.path())
+            .is_none());
+    }
+
+    #[test]
+    fn find_worktree_owner_searches_configured_projects() {
+        let root = tempfile::tempdir().unwrap();
+        let project_a = project("project-a", root.path());
+        let project_b = project("project-b", root.path());
+        fs::create_dir_all(Path::new(&project_b.path).join("worktrees/my-branch")).unwrap();
+
+        let config = MaelstromConfig {
+            projects: vec![project_a, project_b.clone()],
+        };
+
+        let (owner, path) = config
+            .find_worktree_owner("my-branch", root.path())
+            .expect("project-b should own the worktree");
+
+        assert_eq!(owner.name, "project-b");
+        assert_eq!(path, Path::new(&project_b.path).join("worktrees/my-branch"));
+    }
+
+    #[test]
+    fn find_worktree_owner_ignores_fallback_root_when_projects_are_declared() {
+        let root = tempfile::tempdir().unwrap();
+        // The worktree only exists directly under the fallback root, not
+        // under any configured project's own `worktrees` directory.
+        fs::create_dir_all(root.path().join("worktrees/my-branch")).unwrap();
+
+        let config = MaelstromConfig {
+            projects: vec![project("project-a", root.path())],
+        };
+
+        assert!(config
+            .find_worktree_owner("my-branch", root.path())
+            .is_none());
+    }
+}
@@ -0,0 +1,144 @@
+//! Editor/IDE integration: generating `rust-project.json` so
+//! rust-analyzer understands non-standard worktree layouts, launching the
+//! configured editor, and tracking which worktrees currently have an IDE
+//! open against them.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+
+use cargo_metadata::MetadataCommand;
+use serde_json::json;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum IdeError {
+    #[error("failed to run `cargo metadata` in {path}: {source}")]
+    Metadata {
+        path: PathBuf,
+        source: cargo_metadata::Error,
+    },
+    #[error("failed to serialize rust-project.json: {0}")]
+    Serialize(#[from] serde_json::Error),
+    #[error("failed to write {path}: {source}")]
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("failed to launch editor '{editor}': {source}")]
+    Launch {
+        editor: String,
+        source: std::io::Error,
+    },
+}
+
+/// Tracks which worktrees currently have an IDE open, keyed by worktree
+/// name, so `list_all` can report each [`Worktree`](crate::commands::list_all::Worktree)'s `ide_active` flag.
+#[derive(Default)]
+pub struct IdeState(pub Mutex<HashSet<String>>);
+
+impl IdeState {
+    pub fn mark_active(&self, worktree_name: &str) {
+        self.0
+            .lock()
+            .expect("ide state poisoned")
+            .insert(worktree_name.to_string());
+    }
+
+    pub fn active(&self) -> HashSet<String> {
+        self.0.lock().expect("ide state poisoned").clone()
+    }
+}
+
+/// Writes `rust-project.json` into `worktree_path`, describing every
+/// library/binary crate in the cargo workspace (crate roots, inter-crate
+/// deps, and the rustc sysroot source path) so rust-analyzer works even
+/// when the worktree isn't laid out as a standard cargo project.
+pub fn write_rust_project_json(worktree_path: &Path) -> Result<(), IdeError> {
+    let metadata = MetadataCommand::new()
+        .current_dir(worktree_path)
+        .exec()
+        .map_err(|e| IdeError::Metadata {
+            path: worktree_path.to_path_buf(),
+            source: e,
+        })?;
+
+    // Index every lib/bin/proc-macro target so dependency edges below
+    // can reference crates by position, as rust-project.json requires.
+    let mut crates = Vec::new();
+    let mut index_by_name = std::collections::HashMap::new();
+    for package in &metadata.packages {
+        for target in &package.targets {
+            if !target
+                .kind
+                .iter()
+                .any(|k| k == "lib" || k == "bin" || k == "proc-macro")
+            {
+                continue;
+            }
+            index_by_name.insert(target.name.clone(), crates.len());
+            crates.push((package, target));
+        }
+    }
+
+    let crate_entries: Vec<_> = crates
+        .iter()
+        .map(|(package, target)| {
+            let deps: Vec<_> = package
+                .dependencies
+                .iter()
+                .filter_map(|dep| {
+                    index_by_name.get(&dep.name).map(|&index| {
+                        let name = dep.rename.as_deref().unwrap_or(&dep.name);
+                        json!({ "crate": index, "name": name.replace('-', "_") })
+                    })
+                })
+                .collect();
+            let is_workspace_member = metadata.workspace_members.contains(&package.id);
+
+            json!({
+                "root_module": target.src_path,
+                "edition": package.edition,
+                "deps": deps,
+                "cfg": [],
+                "is_workspace_member": is_workspace_member,
+            })
+        })
+        .collect();
+
+    let rust_project = json!({
+        "sysroot_src": sysroot_src(),
+        "crates": crate_entries,
+    });
+
+    let path = worktree_path.join("rust-project.json");
+    let contents = serde_json::to_vec_pretty(&rust_project)?;
+    std::fs::write(&path, contents).map_err(|e| IdeError::Write { path, source: e })
+}
+
+/// Locates the rustc sysroot's bundled source, so rust-analyzer can
+/// resolve `std`/`core` without a `Cargo.toml`-based project.
+fn sysroot_src() -> Option<String> {
+    let output = Command::new("rustc")
+        .args(["--print", "sysroot"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let sysroot = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Some(format!("{sysroot}/lib/rustlib/src/rust/library"))
+}
+
+/// Launches `editor` (e.g. `code`, `zed`) against `worktree_path`.
+pub fn launch_editor(editor: &str, worktree_path: &Path) -> Result<(), IdeError> {
+    Command::new(editor)
+        .arg(worktree_path)
+        .spawn()
+        .map_err(|e| IdeError::Launch {
+            editor: editor.to_string(),
+            source: e,
+        })?;
+    Ok(())
+}
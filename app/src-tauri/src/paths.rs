@@ -0,0 +1,15 @@
+use std::path::PathBuf;
+
+/// Returns the repo root directory (parent of the `app/` directory).
+///
+/// This is the Tauri app's own repo, used as the fallback project root
+/// when `maelstrom.yaml` declares no projects. Worktree paths for a
+/// *configured* project must be resolved against that project's own
+/// `path`, not this one — see [`crate::config::MaelstromConfig::find_worktree_owner`].
+pub fn repo_root() -> PathBuf {
+    let mut dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // CARGO_MANIFEST_DIR points to app/src-tauri, go up twice to reach repo root
+    dir.pop(); // app/
+    dir.pop(); // repo root
+    dir
+}
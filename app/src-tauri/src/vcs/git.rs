@@ -0,0 +1,352 @@
+use std::path::Path;
+
+use git2::{Branch, Repository, StatusOptions, SubmoduleIgnore};
+
+use crate::commands::list_all::{SubmoduleStatus, Worktree};
+
+use super::{Backend, BackendKind, VcsError};
+
+/// [`Backend`] implementation backed by `git2`.
+pub struct GitBackend;
+
+impl Backend for GitBackend {
+    fn kind(&self) -> BackendKind {
+        BackendKind::Git
+    }
+
+    fn discover(&self, project_root: &Path) -> Result<Vec<Worktree>, VcsError> {
+        let worktrees_dir = project_root.join("worktrees");
+        if !worktrees_dir.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let mut worktrees = Vec::new();
+        for entry in std::fs::read_dir(&worktrees_dir).map_err(|e| VcsError::ReadDir {
+            path: worktrees_dir.clone(),
+            source: e,
+        })? {
+            let entry = entry.map_err(|e| VcsError::ReadDir {
+                path: worktrees_dir.clone(),
+                source: e,
+            })?;
+            let path = entry.path();
+            if !path.is_dir() {
+                continue;
+            }
+            let name = entry.file_name().to_string_lossy().into_owned();
+            worktrees.push(worktree_status(name, &path)?);
+        }
+
+        worktrees.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(worktrees)
+    }
+}
+
+fn worktree_status(name: String, path: &Path) -> Result<Worktree, VcsError> {
+    let repo = Repository::open(path).map_err(|e| VcsError::Open {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+
+    let branch = current_branch_name(&repo)?;
+    let dirty_files = count_dirty_files(&repo)?;
+    let (local_commits, pushed_commits) = count_unpushed_and_pushed(&repo)?;
+    let submodules = submodule_statuses(&repo)?;
+
+    Ok(Worktree {
+        folder: name.clone(),
+        name,
+        path: path.to_string_lossy().into_owned(),
+        branch,
+        is_closed: false,
+        dirty_files,
+        local_commits,
+        pr_number: None,
+        pr_commits: None,
+        pushed_commits,
+        ide_active: false,
+        submodules,
+    })
+}
+
+fn current_branch_name(repo: &Repository) -> Result<Option<String>, VcsError> {
+    match repo.head() {
+        Ok(head) => Ok(head.shorthand().map(str::to_string)),
+        Err(_) => Ok(None),
+    }
+}
+
+fn count_dirty_files(repo: &Repository) -> Result<u32, VcsError> {
+    let mut opts = StatusOptions::new();
+    opts.include_untracked(true).recurse_untracked_dirs(true);
+    let statuses = repo.statuses(Some(&mut opts))?;
+    Ok(statuses.len() as u32)
+}
+
+/// Returns `(local_commits, pushed_commits)`: commits on `HEAD` that are
+/// ahead of its upstream (not yet pushed; the count
+/// `git rev-list @{u}..HEAD` gives),
+/// and commits on the upstream branch that are ahead of the repo's
+/// default branch (pushed, but not yet merged).
+fn count_unpushed_and_pushed(repo: &Repository) -> Result<(u32, Option<u32>), VcsError> {
+    let head = repo.head()?;
+    let Some(head_oid) = head.target() else {
+        return Ok((0, None));
+    };
+
+    let Ok(branch) = Branch::wrap(head).upstream() else {
+        return Ok((0, None));
+    };
+    let Some(upstream_oid) = branch.get().target() else {
+        return Ok((0, None));
+    };
+
+    let (ahead, _behind) = repo.graph_ahead_behind(head_oid, upstream_oid)?;
+
+    let pushed = match default_branch_oid(repo) {
+        Some(default_oid) => {
+            let (pushed_ahead, _) = repo.graph_ahead_behind(upstream_oid, default_oid)?;
+            Some(pushed_ahead as u32)
+        }
+        None => None,
+    };
+
+    Ok((ahead as u32, pushed))
+}
+
+/// Finds the tip of the repo's default branch (`origin/HEAD`, falling
+/// back to `origin/main`/`origin/master`), used as the base for counting
+/// commits that have been pushed but not yet merged.
+fn default_branch_oid(repo: &Repository) -> Option<git2::Oid> {
+    for name in [
+        "refs/remotes/origin/HEAD",
+        "refs/remotes/origin/main",
+        "refs/remotes/origin/master",
+    ] {
+        if let Ok(oid) = repo
+            .find_reference(name)
+            .and_then(|r| r.resolve())
+            .and_then(|r| r.target().ok_or_else(|| git2::Error::from_str("no target")))
+        {
+            return Some(oid);
+        }
+    }
+    None
+}
+
+/// Reports, for each submodule declared in `.gitmodules`, whether it has
+/// been checked out (`initialized`) and whether its checkout has local
+/// changes (`dirty`).
+fn submodule_statuses(repo: &Repository) -> Result<Vec<SubmoduleStatus>, VcsError> {
+    let mut statuses = Vec::new();
+    for submodule in repo.submodules()? {
+        let name = submodule.name().unwrap_or_default().to_string();
+        let path = submodule.path().to_string_lossy().into_owned();
+        let status = repo.submodule_status(&name, SubmoduleIgnore::None)?;
+
+        let initialized = !status.is_wd_uninitialized();
+        let dirty = status.is_wd_modified()
+            || status.is_wd_wd_modified()
+            || status.is_wd_index_modified()
+            || status.is_wd_untracked()
+            || status.is_wd_added()
+            || status.is_wd_deleted();
+
+        statuses.push(SubmoduleStatus {
+            name,
+            path,
+            initialized,
+            dirty,
+        });
+    }
+    Ok(statuses)
+}
+
+/// Runs the equivalent of `git submodule update --init --recursive` for
+/// the repository at `worktree_path`, initializing and updating every
+/// submodule (and their nested submodules) in place.
+pub fn sync_submodules(worktree_path: &Path) -> Result<(), VcsError> {
+    let repo = Repository::open(worktree_path).map_err(|e| VcsError::Open {
+        path: worktree_path.to_path_buf(),
+        source: e,
+    })?;
+    sync_submodules_recursive(&repo)
+}
+
+fn sync_submodules_recursive(repo: &Repository) -> Result<(), VcsError> {
+    for mut submodule in repo.submodules()? {
+        submodule.update(true, None)?;
+        if let Ok(sub_repo) = submodule.open() {
+            sync_submodules_recursive(&sub_repo)?;
+        }
+    }
+    Ok(())
+}
+
+/// Creates a new branch from `project_repo`'s `HEAD` and checks it out as
+/// a worktree at `worktree_path` (`git worktree add -b <branch> <path>`).
+pub fn create_worktree(
+    project_repo: &Path,
+    branch: &str,
+    worktree_path: &Path,
+) -> Result<(), VcsError> {
+    let repo = Repository::open(project_repo).map_err(|e| VcsError::Open {
+        path: project_repo.to_path_buf(),
+        source: e,
+    })?;
+
+    let head_commit = repo.head()?.peel_to_commit()?;
+    let branch_ref = repo.branch(branch, &head_commit, false)?;
+
+    let mut opts = git2::WorktreeAddOptions::new();
+    opts.reference(Some(branch_ref.get()));
+
+    repo.worktree(branch, worktree_path, Some(&opts))?;
+
+    Ok(())
+}
+
+/// Pushes the worktree's current branch to `remote_name`, using the local
+/// SSH agent for authentication.
+pub fn push_branch(worktree_path: &Path, remote_name: &str) -> Result<(), VcsError> {
+    let repo = Repository::open(worktree_path).map_err(|e| VcsError::Open {
+        path: worktree_path.to_path_buf(),
+        source: e,
+    })?;
+
+    let head = repo.head()?;
+    let branch_ref = head
+        .name()
+        .ok_or_else(|| VcsError::Git(git2::Error::from_str("HEAD is not a named branch")))?
+        .to_string();
+
+    let mut remote = repo.find_remote(remote_name)?;
+
+    let mut callbacks = git2::RemoteCallbacks::new();
+    callbacks.credentials(|_url, username_from_url, _allowed_types| {
+        git2::Cred::ssh_key_from_agent(username_from_url.unwrap_or("git"))
+    });
+
+    let mut push_opts = git2::PushOptions::new();
+    push_opts.remote_callbacks(callbacks);
+
+    let refspec = format!("{branch_ref}:{branch_ref}");
+    remote.push(&[refspec.as_str()], Some(&mut push_opts))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates a commit with no parents (or `parent`, if given) pointing
+    /// at the repo's current (empty) index, without updating any ref.
+    fn commit(repo: &Repository, message: &str, parent: Option<&git2::Commit>) -> git2::Oid {
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let tree = repo
+            .find_tree(repo.index().unwrap().write_tree().unwrap())
+            .unwrap();
+        let parents: Vec<&git2::Commit> = parent.into_iter().collect();
+        repo.commit(None, &sig, &sig, message, &tree, &parents)
+            .unwrap()
+    }
+
+    #[test]
+    fn default_branch_oid_prefers_origin_head() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+
+        let head_oid = commit(&repo, "head", None);
+        let main_oid = commit(&repo, "main", None);
+        let master_oid = commit(&repo, "master", None);
+        repo.reference("refs/remotes/origin/master", master_oid, true, "test")
+            .unwrap();
+        repo.reference("refs/remotes/origin/main", main_oid, true, "test")
+            .unwrap();
+        repo.reference("refs/remotes/origin/HEAD", head_oid, true, "test")
+            .unwrap();
+
+        assert_eq!(default_branch_oid(&repo), Some(head_oid));
+    }
+
+    #[test]
+    fn default_branch_oid_falls_back_to_origin_main_when_no_origin_head() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+
+        let main_oid = commit(&repo, "main", None);
+        let master_oid = commit(&repo, "master", None);
+        repo.reference("refs/remotes/origin/master", master_oid, true, "test")
+            .unwrap();
+        repo.reference("refs/remotes/origin/main", main_oid, true, "test")
+            .unwrap();
+
+        assert_eq!(default_branch_oid(&repo), Some(main_oid));
+    }
+
+    #[test]
+    fn default_branch_oid_falls_back_to_origin_master_when_no_head_or_main() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+
+        let master_oid = commit(&repo, "master", None);
+        repo.reference("refs/remotes/origin/master", master_oid, true, "test")
+            .unwrap();
+
+        assert_eq!(default_branch_oid(&repo), Some(master_oid));
+    }
+
+    #[test]
+    fn default_branch_oid_is_none_without_any_remote_tracking_ref() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+
+        assert_eq!(default_branch_oid(&repo), None);
+    }
+
+    #[test]
+    fn count_unpushed_and_pushed_counts_commits_ahead_of_upstream() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+
+        let base_oid = commit(&repo, "base", None);
+        repo.reference("refs/heads/master", base_oid, true, "test")
+            .unwrap();
+        repo.set_head("refs/heads/master").unwrap();
+        repo.reference("refs/remotes/origin/master", base_oid, true, "test")
+            .unwrap();
+        // Upstream tracking resolves through a configured remote, so
+        // `origin` must exist for `origin/master` to be the upstream.
+        repo.remote("origin", "https://example.com/repo.git").unwrap();
+
+        let mut branch = Branch::wrap(repo.find_reference("refs/heads/master").unwrap());
+        branch.set_upstream(Some("origin/master")).unwrap();
+
+        let base_commit = repo.find_commit(base_oid).unwrap();
+        let ahead_oid = commit(&repo, "ahead", Some(&base_commit));
+        repo.reference("refs/heads/master", ahead_oid, true, "test")
+            .unwrap();
+
+        let (local_commits, pushed_commits) = count_unpushed_and_pushed(&repo).unwrap();
+        assert_eq!(local_commits, 1);
+        // origin/master, the upstream, is also the only default-branch
+        // candidate here, so nothing has been pushed beyond it.
+        assert_eq!(pushed_commits, Some(0));
+    }
+
+    #[test]
+    fn count_unpushed_and_pushed_is_zero_without_an_upstream() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+
+        let base_oid = commit(&repo, "base", None);
+        repo.reference("refs/heads/master", base_oid, true, "test")
+            .unwrap();
+        repo.set_head("refs/heads/master").unwrap();
+
+        let (local_commits, pushed_commits) = count_unpushed_and_pushed(&repo).unwrap();
+        assert_eq!(local_commits, 0);
+        assert_eq!(pushed_commits, None);
+    }
+}
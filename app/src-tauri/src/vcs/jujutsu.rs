@@ -0,0 +1,21 @@
+use std::path::Path;
+
+use crate::commands::list_all::Worktree;
+
+use super::{Backend, BackendKind, VcsError};
+
+/// Backend for Jujutsu (`.jj`) repos. Worktree discovery is not
+/// implemented: [`Backend::discover`] always returns
+/// [`VcsError::Unsupported`], so such a project is reported as a scan
+/// error rather than listed with its worktrees.
+pub struct JujutsuBackend;
+
+impl Backend for JujutsuBackend {
+    fn kind(&self) -> BackendKind {
+        BackendKind::Jujutsu
+    }
+
+    fn discover(&self, _project_root: &Path) -> Result<Vec<Worktree>, VcsError> {
+        Err(VcsError::Unsupported(BackendKind::Jujutsu))
+    }
+}
@@ -0,0 +1,21 @@
+use std::path::Path;
+
+use crate::commands::list_all::Worktree;
+
+use super::{Backend, BackendKind, VcsError};
+
+/// Backend for Mercurial (`.hg`) repos. Worktree discovery is not
+/// implemented: [`Backend::discover`] always returns
+/// [`VcsError::Unsupported`], so such a project is reported as a scan
+/// error rather than listed with its worktrees.
+pub struct MercurialBackend;
+
+impl Backend for MercurialBackend {
+    fn kind(&self) -> BackendKind {
+        BackendKind::Mercurial
+    }
+
+    fn discover(&self, _project_root: &Path) -> Result<Vec<Worktree>, VcsError> {
+        Err(VcsError::Unsupported(BackendKind::Mercurial))
+    }
+}
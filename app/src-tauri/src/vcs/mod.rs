@@ -0,0 +1,65 @@
+//! Pluggable version control backends.
+//!
+//! `list_all` picks a [`Backend`] per project based on the repo metadata
+//! found at its root (`.git`, `.jj`, `.hg`) and asks it to discover
+//! worktrees and their status. Adding support for another DVCS only
+//! requires a new [`Backend`] implementation and a line in
+//! [`detect_backend`].
+
+pub mod git;
+pub mod jujutsu;
+pub mod mercurial;
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::commands::list_all::Worktree;
+
+#[derive(Debug, Error)]
+pub enum VcsError {
+    #[error("failed to read worktrees directory {path}: {source}")]
+    ReadDir {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("failed to open repository at {path}: {source}")]
+    Open { path: PathBuf, source: git2::Error },
+    #[error("git operation failed: {0}")]
+    Git(#[from] git2::Error),
+    #[error("{0:?} support is not implemented yet")]
+    Unsupported(BackendKind),
+}
+
+/// The kind of DVCS backing a project, as detected from its repo root.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BackendKind {
+    Git,
+    Jujutsu,
+    Mercurial,
+}
+
+/// A source control backend capable of discovering worktrees and their
+/// status for a project.
+pub trait Backend {
+    fn kind(&self) -> BackendKind;
+
+    /// Scans `project_root` and returns a [`Worktree`] per worktree found.
+    fn discover(&self, project_root: &Path) -> Result<Vec<Worktree>, VcsError>;
+}
+
+/// Detects which DVCS a project root uses by checking for `.jj`, `.hg`,
+/// then `.git`, and returns the matching [`Backend`].
+pub fn detect_backend(project_root: &Path) -> Option<Box<dyn Backend>> {
+    if project_root.join(".jj").exists() {
+        Some(Box::new(jujutsu::JujutsuBackend))
+    } else if project_root.join(".hg").exists() {
+        Some(Box::new(mercurial::MercurialBackend))
+    } else if project_root.join(".git").exists() {
+        Some(Box::new(git::GitBackend))
+    } else {
+        None
+    }
+}